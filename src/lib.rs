@@ -0,0 +1,120 @@
+mod settings;
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+use settings::{Settings, ThemePreference, WindowGeometry};
+
+slint::include_modules!();
+
+pub fn run() -> Result<(), Box<dyn Error>> {
+    let ui = AppWindow::new()?;
+    let settings = Rc::new(RefCell::new(Settings::load()));
+
+    if let Some(geometry) = settings.borrow().window {
+        restore_geometry(ui.window(), geometry);
+    }
+    ui.set_theme(settings.borrow().theme.into());
+
+    ui.on_request_increase_value({
+        let ui_handle = ui.as_weak();
+        move || {
+            let ui = ui_handle.unwrap();
+            ui.set_counter(ui.get_counter() + 1);
+        }
+    });
+
+    ui.on_toggle_fullscreen({
+        let ui_handle = ui.as_weak();
+        move || {
+            let ui = ui_handle.unwrap();
+            let window = ui.window();
+            window.set_fullscreen(!window.is_fullscreen());
+        }
+    });
+
+    ui.window().on_close_requested({
+        let ui_handle = ui.as_weak();
+        let settings = settings.clone();
+        move || {
+            let ui = ui_handle.unwrap();
+            let mut settings = settings.borrow_mut();
+            settings.window = capture_geometry(ui.window(), settings.window);
+            settings.theme = ui.get_theme().into();
+            if let Err(err) = settings.save() {
+                eprintln!("Failed to save settings: {err}");
+            }
+            slint::CloseRequestResponse::HideWindow
+        }
+    });
+
+    ui.run()?;
+
+    Ok(())
+}
+
+fn restore_geometry(window: &slint::Window, geometry: WindowGeometry) {
+    if geometry.width == 0 || geometry.height == 0 {
+        return;
+    }
+    window.set_position(slint::PhysicalPosition::new(geometry.x, geometry.y));
+    window.set_size(slint::PhysicalSize::new(geometry.width, geometry.height));
+    window.set_maximized(geometry.maximized);
+}
+
+fn capture_geometry(
+    window: &slint::Window,
+    previous: Option<WindowGeometry>,
+) -> Option<WindowGeometry> {
+    // A fullscreen window reports the monitor's bounds and a minimized one an off-screen
+    // placeholder position (-32000,-32000 on Windows), neither of which is its normal geometry.
+    if window.is_fullscreen() || window.is_minimized() {
+        return previous;
+    }
+    let maximized = window.is_maximized();
+    match previous {
+        Some(previous) if maximized => Some(WindowGeometry {
+            maximized,
+            ..previous
+        }),
+        _ => {
+            let position = window.position();
+            let size = window.size();
+            Some(WindowGeometry {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized,
+            })
+        }
+    }
+}
+
+impl From<ThemePreference> for Theme {
+    fn from(theme: ThemePreference) -> Self {
+        match theme {
+            ThemePreference::System => Theme::System,
+            ThemePreference::Light => Theme::Light,
+            ThemePreference::Dark => Theme::Dark,
+        }
+    }
+}
+
+impl From<Theme> for ThemePreference {
+    fn from(theme: Theme) -> Self {
+        match theme {
+            Theme::System => ThemePreference::System,
+            Theme::Light => ThemePreference::Light,
+            Theme::Dark => ThemePreference::Dark,
+        }
+    }
+}
+
+// Entry point when loaded as a WebAssembly module by the browser.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen(start)]
+pub fn start() {
+    run().unwrap();
+}
@@ -0,0 +1,190 @@
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use serde::{Deserialize, Deserializer, Serialize, de};
+
+/// Application settings persisted as TOML in the platform config directory.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: ThemePreference,
+    pub window: Option<WindowGeometry>,
+}
+
+/// Written in lowercase; read case-insensitively so hand-edited files like `theme = "Dark"` load.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreference {
+    /// Follow the operating system's light/dark setting.
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl<'de> Deserialize<'de> for ThemePreference {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.to_ascii_lowercase().as_str() {
+            "system" => Ok(Self::System),
+            "light" => Ok(Self::Light),
+            "dark" => Ok(Self::Dark),
+            _ => Err(de::Error::unknown_variant(
+                &value,
+                &["system", "light", "dark"],
+            )),
+        }
+    }
+}
+
+/// Size and position of the main window in physical pixels.
+///
+/// While the window is maximized, `x`/`y`/`width`/`height` keep the last non-maximized
+/// geometry so un-maximizing after a restart returns to it. Missing fields default to zero, and
+/// an entry with a zero size is not restored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+}
+
+impl Settings {
+    /// Loads the settings file, falling back to defaults when it is missing or invalid.
+    pub fn load() -> Self {
+        let Some(path) = path() else {
+            return Self::default();
+        };
+        let Ok(text) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        toml::from_str(&text).unwrap_or_else(|err| {
+            eprintln!("Failed to load settings from {}: {err}", path.display());
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let Some(path) = path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        write_atomic(&path, toml::to_string(self)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Writes `contents` to a sibling temp file and renames it over `path`, so a crash mid-write
+/// leaves the previous file intact. The temp name includes the process id so concurrent
+/// instances don't write to the same file.
+fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp = path.with_extension(format!("{}.tmp", process::id()));
+    let result = write_synced(&tmp, contents).and_then(|()| fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+fn write_synced(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn path() -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", "e7Tasks")
+        .map(|dirs| dirs.config_dir().join("settings.toml"))
+}
+
+// Browsers have no config directory; settings are not persisted there.
+#[cfg(target_arch = "wasm32")]
+fn path() -> Option<PathBuf> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let settings = Settings {
+            theme: ThemePreference::Dark,
+            window: Some(WindowGeometry {
+                x: -10,
+                y: 20,
+                width: 800,
+                height: 600,
+                maximized: true,
+            }),
+        };
+        let text = toml::to_string(&settings).unwrap();
+        assert_eq!(toml::from_str::<Settings>(&text).unwrap(), settings);
+    }
+
+    #[test]
+    fn theme_is_written_lowercase_and_read_case_insensitively() {
+        let settings = Settings {
+            theme: ThemePreference::Light,
+            window: None,
+        };
+        let text = toml::to_string(&settings).unwrap();
+        assert_eq!(text.trim(), r#"theme = "light""#);
+        assert_eq!(toml::from_str::<Settings>(&text).unwrap(), settings);
+
+        let settings: Settings = toml::from_str(r#"theme = "Dark""#).unwrap();
+        assert_eq!(settings.theme, ThemePreference::Dark);
+        assert!(toml::from_str::<Settings>(r#"theme = "sepia""#).is_err());
+    }
+
+    #[test]
+    fn empty_file_loads_defaults() {
+        assert_eq!(toml::from_str::<Settings>("").unwrap(), Settings::default());
+    }
+
+    #[test]
+    fn window_table_alone_loads_defaults_for_missing_fields() {
+        let settings: Settings = toml::from_str("[window]\nwidth = 800\nheight = 600\n").unwrap();
+        assert_eq!(settings.theme, ThemePreference::System);
+        assert_eq!(
+            settings.window,
+            Some(WindowGeometry {
+                width: 800,
+                height: 600,
+                ..WindowGeometry::default()
+            })
+        );
+
+        let settings: Settings = toml::from_str("[window]\n").unwrap();
+        assert_eq!(settings.window, Some(WindowGeometry::default()));
+    }
+
+    #[test]
+    fn write_atomic_replaces_file_without_leaving_temp_files() {
+        let dir = std::env::temp_dir().join(format!("e7tasks-write-atomic-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("settings.toml");
+        fs::write(&path, "a much longer previous file content\n").unwrap();
+
+        write_atomic(&path, b"short\n").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "short\n");
+        let entries: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, ["settings.toml"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}